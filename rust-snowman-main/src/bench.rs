@@ -0,0 +1,114 @@
+/*
+ * Benchmark subsystem: runs the automatic solver once per secret word
+ * and aggregates win rate and wrong-guess statistics, so a maintainer
+ * can tell whether a dictionary or solver-strategy change actually
+ * improves play.
+ */
+
+use crate::solver::{self, SolveResult};
+use rayon::prelude::*;
+use std::collections::HashMap;
+
+/// Aggregate statistics from running the solver across many secret words.
+pub struct BenchSummary {
+    pub total: usize,
+    pub wins: usize,
+    pub mean_wrong_guesses: f64,
+    pub median_wrong_guesses: f64,
+    pub wrong_guess_distribution: Vec<(u32, usize)>,
+    pub worst_words: Vec<String>,
+}
+
+/// Runs the solver once per secret word - the first `n` words, or the
+/// whole dictionary if `n` is `None` - and aggregates the results. Each
+/// game is independent, so the simulations run in parallel via rayon.
+///
+/// # Arguments
+/// * `dictionary` - Word list the solver draws candidates from; also the words benchmarked
+/// * `attempts` - Number of wrong guesses the solver is allowed per word
+/// * `n` - How many words to benchmark, or `None` for the whole dictionary
+pub fn run(dictionary: &[String], attempts: u32, n: Option<usize>) -> BenchSummary {
+    let word_count: usize = n.unwrap_or(dictionary.len()).min(dictionary.len());
+    let words: &[String] = &dictionary[..word_count];
+
+    let results: Vec<(&String, SolveResult)> = words
+        .par_iter()
+        .map(|word| (word, solver::solve(word, dictionary, attempts, false)))
+        .collect();
+
+    let total: usize = results.len();
+    let wins: usize = results.iter().filter(|(_, result)| result.solved).count();
+
+    let mut wrong_counts: Vec<u32> = results.iter().map(|(_, result)| result.wrong_guesses).collect();
+    wrong_counts.sort_unstable();
+
+    let mean_wrong_guesses: f64 = if total > 0 {
+        wrong_counts.iter().map(|&count| count as f64).sum::<f64>() / total as f64
+    } else {
+        0.0
+    };
+    let median_wrong_guesses: f64 = if wrong_counts.is_empty() {
+        0.0
+    } else if wrong_counts.len() % 2 == 1 {
+        wrong_counts[wrong_counts.len() / 2] as f64
+    } else {
+        let mid: usize = wrong_counts.len() / 2;
+        (wrong_counts[mid - 1] as f64 + wrong_counts[mid] as f64) / 2.0
+    };
+
+    let mut distribution: HashMap<u32, usize> = HashMap::new();
+    for &count in &wrong_counts {
+        *distribution.entry(count).or_insert(0) += 1;
+    }
+    let mut wrong_guess_distribution: Vec<(u32, usize)> = distribution.into_iter().collect();
+    wrong_guess_distribution.sort_by_key(|&(count, _)| count);
+
+    let mut worst_words: Vec<String> = results
+        .iter()
+        .filter(|(_, result)| !result.solved)
+        .map(|(word, _)| (*word).clone())
+        .collect();
+    worst_words.sort();
+
+    BenchSummary {
+        total,
+        wins,
+        mean_wrong_guesses,
+        median_wrong_guesses,
+        wrong_guess_distribution,
+        worst_words,
+    }
+}
+
+/// Prints a human-readable summary table for a `BenchSummary`.
+pub fn print_summary(summary: &BenchSummary) {
+    let win_rate: f64 = if summary.total > 0 {
+        summary.wins as f64 / summary.total as f64 * 100.0
+    } else {
+        0.0
+    };
+
+    println!();
+    println!("Solver benchmark over {} words", summary.total);
+    println!("-----------------------------------------");
+    println!("Win rate:             {:.1}% ({}/{})", win_rate, summary.wins, summary.total);
+    println!("Mean wrong guesses:   {:.2}", summary.mean_wrong_guesses);
+    println!("Median wrong guesses: {:.1}", summary.median_wrong_guesses);
+    println!();
+    println!("Wrong-guess distribution:");
+    for (wrong_guesses, frequency) in &summary.wrong_guess_distribution {
+        println!("  {:>2} wrong guesses: {}", wrong_guesses, frequency);
+    }
+
+    if !summary.worst_words.is_empty() {
+        const MAX_LISTED: usize = 20;
+        println!();
+        println!("Words the solver never cracked ({}):", summary.worst_words.len());
+        for word in summary.worst_words.iter().take(MAX_LISTED) {
+            println!("  {}", word);
+        }
+        if summary.worst_words.len() > MAX_LISTED {
+            println!("  ... and {} more", summary.worst_words.len() - MAX_LISTED);
+        }
+    }
+}
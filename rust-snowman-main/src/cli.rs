@@ -0,0 +1,184 @@
+/*
+ * Command-line configuration for Snowman.
+ *
+ * Keeps the difficulty knobs (word length window, number of wrong
+ * guesses allowed) in one place so `main` can stay focused on the game
+ * loop itself.
+ */
+
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser, Subcommand};
+
+/// Shortest word length we'll ever accept, regardless of what the player asks for.
+const MIN_WORD_LEN_FLOOR: usize = 2;
+/// Longest word length we'll ever accept, regardless of what the player asks for.
+const MAX_WORD_LEN_CEILING: usize = 30;
+/// Fewest wrong guesses the player can be given before losing.
+const MIN_ATTEMPTS: u32 = 1;
+/// Most wrong guesses the player can be given before losing.
+const MAX_ATTEMPTS: u32 = 12;
+/// Dictionary used when the player doesn't pick one with `--dict`.
+const DEFAULT_DICTIONARY: &str = "default";
+
+/// Command-line options controlling the difficulty of a Snowman game.
+#[derive(Parser, Debug)]
+#[command(name = "snowman", about = "A terminal-based word guessing game")]
+pub struct Cli {
+    /// Shortest word the secret word may be
+    #[arg(long, default_value_t = MIN_WORD_LEN_FLOOR, value_parser = parse_word_len)]
+    pub min_len: usize,
+
+    /// Longest word the secret word may be
+    #[arg(long, default_value_t = MAX_WORD_LEN_CEILING, value_parser = parse_word_len)]
+    pub max_len: usize,
+
+    /// Number of wrong guesses allowed before the snowman is finished
+    #[arg(long, default_value_t = 6, value_parser = parse_attempts)]
+    pub attempts: u32,
+
+    /// Play automatically using a candidate-frequency solver instead of prompting a human
+    #[arg(long)]
+    pub solve: bool,
+
+    /// Resume the game saved from a previous run, if one exists
+    #[arg(long)]
+    pub resume: bool,
+
+    /// Benchmark the solver across the dictionary instead of playing a single game
+    #[arg(long)]
+    pub bench: bool,
+
+    /// Number of words to benchmark against (defaults to the whole dictionary)
+    #[arg(long)]
+    pub bench_n: Option<usize>,
+
+    /// Named word list to play from (see `--list-dicts`)
+    #[arg(long, default_value = DEFAULT_DICTIONARY)]
+    pub dict: String,
+
+    /// List the available named dictionaries and exit
+    #[arg(long)]
+    pub list_dicts: bool,
+
+    /// Create a new, empty named dictionary and exit
+    #[arg(long, value_name = "NAME")]
+    pub create_dict: Option<String>,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// A subcommand selecting an alternate way to play Snowman.
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Play interactively through a REPL instead of the default linear prompt loop
+    Repl,
+}
+
+fn parse_word_len(s: &str) -> Result<usize, String> {
+    let len: usize = s
+        .parse()
+        .map_err(|_| format!("'{}' isn't a valid word length", s))?;
+    if (MIN_WORD_LEN_FLOOR..=MAX_WORD_LEN_CEILING).contains(&len) {
+        Ok(len)
+    } else {
+        Err(format!(
+            "word length must be between {} and {}",
+            MIN_WORD_LEN_FLOOR, MAX_WORD_LEN_CEILING
+        ))
+    }
+}
+
+fn parse_attempts(s: &str) -> Result<u32, String> {
+    let attempts: u32 = s
+        .parse()
+        .map_err(|_| format!("'{}' isn't a valid number of attempts", s))?;
+    if (MIN_ATTEMPTS..=MAX_ATTEMPTS).contains(&attempts) {
+        Ok(attempts)
+    } else {
+        Err(format!(
+            "attempts must be between {} and {}",
+            MIN_ATTEMPTS, MAX_ATTEMPTS
+        ))
+    }
+}
+
+impl Cli {
+    /// Parses `std::env::args`, printing usage and exiting non-zero on
+    /// anything clap's own `value_parser`s can't catch (e.g. `min_len`
+    /// and `max_len` crossing each other).
+    pub fn parse_and_validate() -> Self {
+        let cli = Self::parse();
+        if let Err(e) = cli.validate() {
+            Self::command().error(ErrorKind::ArgumentConflict, e).exit();
+        }
+        cli
+    }
+
+    /// Cross-field validation clap's own `value_parser`s can't express.
+    fn validate(&self) -> Result<(), String> {
+        if self.min_len > self.max_len {
+            return Err("--min-len cannot be greater than --max-len".to_string());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_word_len_accepts_in_range() {
+        assert_eq!(parse_word_len("5"), Ok(5));
+    }
+
+    #[test]
+    fn parse_word_len_rejects_non_numeric() {
+        assert!(parse_word_len("abc").is_err());
+    }
+
+    #[test]
+    fn parse_word_len_rejects_out_of_range() {
+        assert!(parse_word_len("0").is_err());
+        assert!(parse_word_len("31").is_err());
+    }
+
+    #[test]
+    fn parse_attempts_accepts_in_range() {
+        assert_eq!(parse_attempts("6"), Ok(6));
+    }
+
+    #[test]
+    fn parse_attempts_rejects_out_of_range() {
+        assert!(parse_attempts("0").is_err());
+        assert!(parse_attempts("13").is_err());
+    }
+
+    fn sample_cli(min_len: usize, max_len: usize) -> Cli {
+        Cli {
+            min_len,
+            max_len,
+            attempts: 6,
+            solve: false,
+            resume: false,
+            bench: false,
+            bench_n: None,
+            dict: DEFAULT_DICTIONARY.to_string(),
+            list_dicts: false,
+            create_dict: None,
+            command: None,
+        }
+    }
+
+    #[test]
+    fn validate_accepts_min_len_at_most_max_len() {
+        assert!(sample_cli(3, 7).validate().is_ok());
+        assert!(sample_cli(5, 5).validate().is_ok());
+    }
+
+    #[test]
+    fn validate_rejects_min_len_above_max_len() {
+        assert!(sample_cli(8, 3).validate().is_err());
+    }
+}
@@ -0,0 +1,108 @@
+/*
+ * Named dictionaries: resolves a dictionary name (e.g. "animals",
+ * "countries") to its word-list file under `src/dictionaries/`, instead
+ * of pinning every game to a single `src/dictionary.txt`.
+ */
+
+use std::fs;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Directory holding every named dictionary's word-list file.
+const DICTIONARY_DIR: &str = "src/dictionaries";
+/// Shortest word a dictionary entry is allowed to be; shorter entries are malformed.
+const MIN_WORD_LEN: usize = 2;
+
+/// Resolves a dictionary name to its file path.
+fn path_for(name: &str) -> PathBuf {
+    Path::new(DICTIONARY_DIR).join(format!("{}.txt", name))
+}
+
+/// Reads the named dictionary, creating an empty one if it doesn't exist yet.
+///
+/// Entries shorter than `MIN_WORD_LEN` are skipped with a warning instead
+/// of being loaded and silently breaking gameplay later.
+///
+/// # Panics
+/// Panics if the dictionary directory or file cannot be created, opened, or read.
+pub fn read_dictionary(name: &str) -> Vec<String> {
+    let path: PathBuf = path_for(name);
+
+    if !path.exists() {
+        fs::create_dir_all(DICTIONARY_DIR).expect("Couldn't create dictionary directory");
+        File::create(&path).expect("Couldn't create dictionary file");
+    }
+
+    let contents: String = fs::read_to_string(&path).expect("Couldn't read dictionary");
+
+    contents
+        .lines()
+        .filter_map(|line| {
+            let word: String = line.to_string();
+            if word.chars().count() < MIN_WORD_LEN {
+                eprintln!(
+                    "Warning: skipping malformed entry '{}' in dictionary '{}'",
+                    word, name
+                );
+                None
+            } else {
+                Some(word)
+            }
+        })
+        .collect()
+}
+
+/// Appends a new word to the named dictionary.
+///
+/// # Errors
+/// Prints an error message to stderr if the write operation fails.
+pub fn write_dictionary(name: &str, word: &str) {
+    let path: PathBuf = path_for(name);
+
+    let mut file: File = OpenOptions::new()
+        .append(true)
+        .open(&path)
+        .expect("Couldn't open dictionary file");
+
+    if let Err(e) = writeln!(file, "{}", word) {
+        eprintln!("Error writing to dictionary: {}", e);
+    }
+}
+
+/// Lists the names of every dictionary available in the dictionary directory.
+pub fn list_dictionaries() -> Vec<String> {
+    let dir: &Path = Path::new(DICTIONARY_DIR);
+    if !dir.exists() {
+        return Vec::new();
+    }
+
+    let mut names: Vec<String> = fs::read_dir(dir)
+        .expect("Couldn't read dictionary directory")
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            entry
+                .path()
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+/// Creates a new, empty dictionary with the given name.
+///
+/// Does nothing but report the fact if a dictionary with that name already exists.
+pub fn create_dictionary(name: &str) {
+    let path: PathBuf = path_for(name);
+
+    if path.exists() {
+        println!("Dictionary '{}' already exists.", name);
+        return;
+    }
+
+    fs::create_dir_all(DICTIONARY_DIR).expect("Couldn't create dictionary directory");
+    File::create(&path).expect("Couldn't create dictionary file");
+    println!("Created empty dictionary '{}'.", name);
+}
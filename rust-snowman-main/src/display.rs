@@ -0,0 +1,53 @@
+/*
+ * Wordle-style color coding for guessed letters.
+ *
+ * Snowman only ever guesses bare letters (never whole words), so in
+ * practice a guess is either `Correct` or `Absent` - `WrongPosition` is
+ * kept around for the day the game grows full-word guesses and needs to
+ * say "right letter, wrong slot".
+ */
+
+use colored::Colorize;
+use std::fmt;
+
+/// Status of a single guessed letter, used to color-code feedback.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The letter was guessed and appears in the secret word.
+    Correct,
+    /// Reserved for future word-level guesses: present, but not in the guessed slot.
+    /// Never constructed today since Snowman only ever guesses bare letters.
+    #[allow(dead_code)]
+    WrongPosition,
+    /// The letter was guessed and does not appear in the secret word.
+    Absent,
+}
+
+/// A single guessed letter paired with the status of that guess.
+#[derive(Debug, Clone, Copy)]
+pub struct LetterStatus {
+    pub letter: char,
+    pub status: Status,
+}
+
+impl fmt::Display for LetterStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let letter: String = self.letter.to_string();
+        match self.status {
+            Status::Correct => write!(f, "{}", letter.green()),
+            Status::WrongPosition => write!(f, "{}", letter.yellow()),
+            Status::Absent => write!(f, "{}", letter.red().dimmed()),
+        }
+    }
+}
+
+/// A collection of guessed letters, rendered as a space-separated,
+/// color-coded summary.
+pub struct GuessedLetters(pub Vec<LetterStatus>);
+
+impl fmt::Display for GuessedLetters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rendered: Vec<String> = self.0.iter().map(|ls| ls.to_string()).collect();
+        write!(f, "{}", rendered.join(" "))
+    }
+}
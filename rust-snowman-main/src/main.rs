@@ -20,12 +20,21 @@
  */
 
  use rand::prelude::*;
- use std::fs::File;
  use std::io::{prelude::*, stdin};
- use std::path::Path;
- use std::fs::OpenOptions;
  use std::process::exit;
- 
+
+ mod bench;
+ mod cli;
+ mod dictionary;
+ mod display;
+ mod repl;
+ mod session;
+ mod solver;
+
+ use cli::{Cli, Command};
+ use colored::Colorize;
+ use display::{GuessedLetters, LetterStatus, Status};
+
  /// Prints the snowman's hat (appears on 4th wrong guess)
  fn print_hat() {
      println!("    ,===.     ");
@@ -86,53 +95,50 @@
      println!("     __.-`._____.'-.__");
  }
  
- /// Reads the dictionary file and returns a vector of words.
- /// Creates the dictionary file if it doesn't exist.
- /// 
- /// # Returns
- /// A `Vec<String>` containing all words from the dictionary, one per line.
- /// 
- /// # Panics
- /// Panics if the file cannot be created, opened, or read.
- fn read_dictionary() -> Vec<String> {
-     let path: &Path = Path::new("src/dictionary.txt");
-     let mut contents: String = String::new();
- 
-     // Create the dictionary file if it doesn't exist
-     if !path.exists() {
-         File::create(path).expect("Couldn't create dictionary file");
+ /// Draws the snowman at the stage corresponding to `tries` wrong guesses
+ /// out of `attempts` allowed, scaling the six fixed art stages to
+ /// whatever attempt count the player configured.
+ ///
+ /// # Arguments
+ /// * `tries` - Number of wrong guesses made so far (1-indexed, at least 1)
+ /// * `attempts` - Total number of wrong guesses allowed before losing
+ pub(crate) fn draw_snowman(tries: u32, attempts: u32) {
+     let stage: u64 = ((tries as u64 * 6) / attempts as u64).clamp(1, 6);
+     match stage {
+         1 => print_bottom(),
+         2 => {
+             print_torso();
+             print_bottom();
+         }
+         3 => {
+             print_head();
+             print_torso();
+             print_bottom();
+         }
+         4 => {
+             print_hat();
+             print_head();
+             print_torso();
+             print_bottom();
+         }
+         5 => print_right_arm(),
+         _ => print_left_arm(),
      }
- 
-     // Open and read the dictionary file
-     File::open(&path)
-         .expect("Couldn't open dictionary")
-         .read_to_string(&mut contents)
-         .expect("Couldn't read dictionary");
- 
-     // Split the contents by newlines and collect into a vector
-     contents.lines().map(|s: &str| s.to_string()).collect()
  }
- 
- /// Appends a new word to the dictionary file.
- /// 
+
+ /// Filters a dictionary down to words whose length falls within
+ /// `min_len..=max_len`, inclusive.
+ ///
  /// # Arguments
- /// * `word` - The word to add to the dictionary
- /// 
- /// # Errors
- /// Prints an error message to stderr if the write operation fails.
- fn write_dictionary(word: &str) {
-     let path: &str = "src/dictionary.txt";
- 
-     // Open the file in append mode
-     let mut file: File = OpenOptions::new()
-         .append(true)
-         .open(path)
-         .expect("Couldn't open dictionary file");
- 
-     // Write the word followed by a newline
-     if let Err(e) = writeln!(file, "{}", word) {
-         eprintln!("Error writing to dictionary: {}", e);
-     }
+ /// * `dictionary` - The full word list to filter
+ /// * `min_len` - Shortest acceptable word length
+ /// * `max_len` - Longest acceptable word length
+ fn words_in_length_range(dictionary: &[String], min_len: usize, max_len: usize) -> Vec<String> {
+     dictionary
+         .iter()
+         .filter(|word| (min_len..=max_len).contains(&word.chars().count()))
+         .cloned()
+         .collect()
  }
  
  /// Prompts the user for input and returns their response.
@@ -145,7 +151,7 @@
  /// 
  /// # Panics
  /// Panics if stdout cannot be flushed or input cannot be read.
- fn get_user_input(prompt: &str) -> String {
+ pub(crate) fn get_user_input(prompt: &str) -> String {
      print!("{}", prompt);
      std::io::stdout().flush().expect("Failed to flush stdout");
      let mut input: String = String::new();
@@ -156,10 +162,11 @@
  /// Interactive mode for adding new words to the dictionary.
  /// Allows users to add multiple words separated by spaces.
  /// Type '1' to exit this mode.
- /// 
+ ///
  /// # Arguments
  /// * `dictionary` - Reference to the current dictionary for validation
- fn add_new_words_to_dictionary(dictionary: &Vec<String>) {
+ /// * `dict_name` - Name of the dictionary currently selected, for persisting new words
+ fn add_new_words_to_dictionary(dictionary: &[String], dict_name: &str) {
      println!("Enter new valid words to be added to the dictionary, separated by spaces, or press 1 to exit");
  
      let mut exit: bool = false;
@@ -179,7 +186,7 @@
              for word in words {
                  match validate_new_word(word, dictionary) {
                      Ok(_) => {
-                         write_dictionary(word);
+                         dictionary::write_dictionary(dict_name, word);
                          println!("Added {} to the dictionary!", word);
                      }
                      Err(_) => {
@@ -188,7 +195,7 @@
                  }
              }
          }
-         println!("");
+         println!();
          println!("Enter new valid words to be added to the dictionary, separated by spaces, or press 1 to exit");
      }
  }
@@ -207,7 +214,7 @@
  /// - Must be exactly one character
  /// - Must be alphabetic or a hyphen/apostrophe
  /// - Cannot be a previously guessed letter
- fn validate_guess<'a>(guess: &'a str, guessed_letters: &'a Vec<String>) -> Result<(), &'a str> {
+ pub(crate) fn validate_guess<'a>(guess: &'a str, guessed_letters: &'a [String]) -> Result<(), &'a str> {
      if guess.chars().count() != 1 {
          return Err("Please enter a single letter!");
      }
@@ -234,7 +241,7 @@
  /// - Must be at least 2 characters long
  /// - Can only contain letters, hyphens, or apostrophes
  /// - Cannot already exist in the dictionary
- fn validate_new_word<'a>(word: &'a str, dictionary: &'a Vec<String>) -> Result<(), &'a str> {
+ pub(crate) fn validate_new_word<'a>(word: &'a str, dictionary: &'a [String]) -> Result<(), &'a str> {
      if word.chars().count() < 2 {
          return Err("Please enter a word with at least 2 characters!");
      }
@@ -248,45 +255,123 @@
  }
  
  /// Main game loop - handles the Snowman game logic.
- /// 
+ ///
  /// # Game Flow
- /// 1. Loads dictionary from file
- /// 2. Selects a random word
- /// 3. Player guesses letters one at a time
- /// 4. Snowman builds up with each wrong guess (6 tries total)
- /// 5. Player wins by guessing all letters or loses after 6 wrong guesses
- /// 6. Offers option to add new words to dictionary
+ /// 1. Parses difficulty settings (word length window, attempt count)
+ /// 2. Loads the selected named dictionary and narrows it to the length window
+ /// 3. Selects a random word
+ /// 4. Player guesses letters one at a time
+ /// 5. Snowman builds up with each wrong guess (configurable number of tries)
+ /// 6. Player wins by guessing all letters or loses after running out of tries
+ /// 7. Offers option to add new words to dictionary
  fn main() {
-     // Read the dictionary file
-     let dictionary: Vec<String> = read_dictionary();
- 
+     // Parse and validate the difficulty settings for this game
+     let cli: Cli = Cli::parse_and_validate();
+
+     // List the available named dictionaries and exit, if asked
+     if cli.list_dicts {
+         for name in dictionary::list_dictionaries() {
+             println!("{}", name);
+         }
+         return;
+     }
+
+     // Create a new, empty named dictionary and exit, if asked
+     if let Some(name) = &cli.create_dict {
+         dictionary::create_dictionary(name);
+         return;
+     }
+
+     // Read the selected named dictionary
+     let mut dictionary: Vec<String> = dictionary::read_dictionary(&cli.dict);
+
      // Check if the dictionary is empty - can't play without words!
      if dictionary.is_empty() {
          println!("The dictionary is empty. Please add words to play the game.");
-         add_new_words_to_dictionary(&dictionary);
+         add_new_words_to_dictionary(&dictionary, &cli.dict);
          return;
      }
  
-     // Choose a random word from the dictionary for this game
-     let word: &String = dictionary.choose(&mut rand::rng()).unwrap();
+     // In bench mode, score the solver across the dictionary instead of playing a game
+     if cli.bench {
+         let summary = bench::run(&dictionary, cli.attempts, cli.bench_n);
+         bench::print_summary(&summary);
+         return;
+     }
+ 
+     // Narrow the dictionary down to the requested word-length window
+     let candidates: Vec<String> = words_in_length_range(&dictionary, cli.min_len, cli.max_len);
+
+     if candidates.is_empty() {
+         println!(
+             "No words between {} and {} letters long. Please add some or widen the range.",
+             cli.min_len, cli.max_len
+         );
+         add_new_words_to_dictionary(&dictionary, &cli.dict);
+         return;
+     }
+ 
+     // Resume a saved game if requested and still valid
+     let resumed: Option<session::GameState> = if cli.resume {
+         session::GameState::load(&dictionary)
+     } else {
+         None
+     };
+
+     // Choose a random word from the filtered candidates, unless resuming
+     let word: String = match &resumed {
+         Some(state) => {
+             println!("Resuming saved game...");
+             state.secret.clone()
+         }
+         None => candidates.choose(&mut rand::rng()).unwrap().clone(),
+     };
+
+     // In solve mode, let the automatic solver play against the chosen word
+     // instead of prompting a human
+     if cli.solve {
+         solver::solve(&word, &dictionary, cli.attempts, true);
+         return;
+     }
+
+     // In REPL mode, hand the chosen word off to the command-driven interface
+     if let Some(Command::Repl) = cli.command {
+         repl::run(&word, &mut dictionary, cli.attempts, resumed, &cli.dict);
+         return;
+     }
  
      println!("Welcome to Snowman!");
-     
-     // Display initial underscores for each letter in the word
-     word.chars().for_each(|_| {
-         print!("_ ");
+ 
+     // Initialize game state variables, picking up where a resumed game left off
+     let mut guessed_letters: Vec<String> = resumed
+         .as_ref()
+         .map(|state| state.guessed_letters.clone())
+         .unwrap_or_default();
+     let mut tries: u32 = resumed.as_ref().map(|state| state.tries).unwrap_or(0);
+     let attempts: u32 = resumed.as_ref().map(|state| state.attempts).unwrap_or(cli.attempts);
+     let mut guessed: bool = word
+         .chars()
+         .all(|c: char| guessed_letters.contains(&c.to_string()));
+ 
+     // Display the word's current progress (blank unless resuming)
+     word.chars().for_each(|c: char| {
+         if guessed_letters.contains(&c.to_string()) {
+             print!("{} ", c.to_string().green());
+         } else {
+             print!("_ ");
+         }
      });
  
-     println!("");
+     println!();
  
-     // Initialize game state variables
-     let mut guessed: bool = false;
-     let mut guessed_letters: Vec<String> = Vec::new();
-     let mut tries: i32 = 0;
+     // Redraw the snowman at its current stage when resuming mid-game
+     if tries > 0 {
+         draw_snowman(tries, attempts);
+     }
  
      // Main game loop - continues until player wins or loses
      while !guessed {
-         println!("");
+         println!();
          let guess: String = get_user_input("Guess a letter: ");
          
          // Validate the guess before processing
@@ -303,10 +388,10 @@
  
          let mut display_word: String = String::new();
  
-         // Build display string showing guessed letters and blanks
+         // Build display string showing guessed letters (green) and blanks (neutral)
          word.chars().for_each(|c: char| {
              if guessed_letters.contains(&c.to_string()) {
-                 display_word.push_str(&format!("{} ", c));
+                 display_word.push_str(&format!("{} ", c.to_string().green()));
              } else {
                  display_word.push_str("_ ");
              }
@@ -317,56 +402,63 @@
              guessed = true;
          }
          
-         println!("");
+         println!();
          println!("{}", display_word);
-         println!("");
+         println!();
  
          // Handle wrong guesses - build the snowman progressively
          if !word.contains(&guess) {
              tries += 1;
-             if tries == 1 {
-                 print_bottom();
-             } else if tries == 2 {
-                 print_torso();
-                 print_bottom();
-             } else if tries == 3 {
-                 print_head();
-                 print_torso();
-                 print_bottom();
-             } else if tries == 4 {
-                 print_hat();
-                 print_head();
-                 print_torso();
-                 print_bottom();
-             } else if tries == 5 {
-                 print_right_arm();
-                 
-             } else if tries == 6 {
-                 print_left_arm();
-             }
-             println!("");
-             println!("Wrong guess! You have {} tries left", 6 - tries);
+             draw_snowman(tries, attempts);
+             println!();
+             println!("Wrong guess! You have {} tries left", attempts - tries);
          }
  
          // Sort guessed letters alphabetically for better display
          guessed_letters.sort();
  
-         // Display all guessed letters so far
-         println!("");
-         println!("guessed Letters: {}", guessed_letters.join(" "));
+         // Tint each guessed letter by whether it was in the word
+         let statuses: GuessedLetters = GuessedLetters(
+             guessed_letters
+                 .iter()
+                 .map(|letter: &String| LetterStatus {
+                     letter: letter.chars().next().unwrap(),
+                     status: if word.contains(letter) {
+                         Status::Correct
+                     } else {
+                         Status::Absent
+                     },
+                 })
+                 .collect(),
+         );
  
+         // Display all guessed letters so far
+         println!();
+         println!("guessed Letters: {}", statuses);
+ 
+         // Persist progress so the game can be resumed if it's interrupted
+         session::GameState {
+             secret: word.clone(),
+             guessed_letters: guessed_letters.clone(),
+             tries,
+             attempts,
+         }
+         .save();
+
          // Check for game over conditions
-         if tries == 6 {
+         if tries == attempts {
              println!("You lost. The word was {}", word);
+             session::GameState::clear();
              break;
          }
          if guessed {
              println!("You won! The word was {}", word);
+             session::GameState::clear();
              break;
          }
      }
  
-     println!("");
+     println!();
  
      // Ask the user if they would like to add new words to the dictionary
      loop {
@@ -382,7 +474,7 @@
      }
  
      // Enter dictionary management mode
-     add_new_words_to_dictionary(&dictionary);
-     println!("");
+     add_new_words_to_dictionary(&dictionary, &cli.dict);
+     println!();
      println!("Thank you for playing!");
  }
\ No newline at end of file
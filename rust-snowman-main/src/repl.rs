@@ -0,0 +1,244 @@
+/*
+ * Interactive REPL mode: lets the player drive a game (or the
+ * dictionary) one typed command at a time instead of the fixed
+ * guess-then-prompt loop in `main`.
+ */
+
+use crate::dictionary;
+use crate::display::{GuessedLetters, LetterStatus, Status};
+use crate::session::GameState;
+use crate::solver;
+use crate::{draw_snowman, get_user_input, validate_guess, validate_new_word};
+use colored::Colorize;
+use std::collections::HashSet;
+
+/// A single command typed at the REPL prompt.
+enum Command {
+    Guess(String),
+    State,
+    Hint,
+    Add(Vec<String>),
+    Save,
+    Quit,
+    Unknown(String),
+}
+
+fn parse_command(line: &str) -> Command {
+    let mut parts = line.split_whitespace();
+    match parts.next() {
+        Some("guess") => match parts.next() {
+            Some(letter) => Command::Guess(letter.to_string()),
+            None => Command::Unknown(line.to_string()),
+        },
+        Some("state") => Command::State,
+        Some("hint") => Command::Hint,
+        Some("add") => Command::Add(parts.map(|word| word.to_string()).collect()),
+        Some("save") => Command::Save,
+        Some("quit") | Some("exit") => Command::Quit,
+        _ => Command::Unknown(line.to_string()),
+    }
+}
+
+/// State for a single in-progress game, tracked between REPL commands.
+struct Session {
+    secret: String,
+    guessed_letters: Vec<String>,
+    tries: u32,
+    attempts: u32,
+}
+
+impl Session {
+    fn pattern(&self) -> Vec<Option<char>> {
+        self.secret
+            .chars()
+            .map(|c| {
+                if self.guessed_letters.contains(&c.to_string()) {
+                    Some(c)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    fn guessed_set(&self) -> HashSet<char> {
+        self.guessed_letters
+            .iter()
+            .map(|letter| letter.chars().next().unwrap())
+            .collect()
+    }
+
+    fn absent_set(&self) -> HashSet<char> {
+        self.guessed_letters
+            .iter()
+            .filter(|letter| !self.secret.contains(letter.as_str()))
+            .map(|letter| letter.chars().next().unwrap())
+            .collect()
+    }
+
+    fn is_won(&self) -> bool {
+        self.secret
+            .chars()
+            .all(|c| self.guessed_letters.contains(&c.to_string()))
+    }
+
+    fn is_lost(&self) -> bool {
+        self.tries >= self.attempts
+    }
+
+    fn print_state(&self) {
+        let display_word: String = self
+            .secret
+            .chars()
+            .map(|c| {
+                if self.guessed_letters.contains(&c.to_string()) {
+                    format!("{} ", c.to_string().green())
+                } else {
+                    "_ ".to_string()
+                }
+            })
+            .collect();
+        println!("{}", display_word);
+
+        let statuses = GuessedLetters(
+            self.guessed_letters
+                .iter()
+                .map(|letter| LetterStatus {
+                    letter: letter.chars().next().unwrap(),
+                    status: if self.secret.contains(letter) {
+                        Status::Correct
+                    } else {
+                        Status::Absent
+                    },
+                })
+                .collect(),
+        );
+        println!("guessed Letters: {}", statuses);
+        println!("Wrong guesses: {}/{}", self.tries, self.attempts);
+        if self.tries > 0 {
+            draw_snowman(self.tries, self.attempts);
+        }
+    }
+
+    fn to_game_state(&self) -> GameState {
+        GameState {
+            secret: self.secret.clone(),
+            guessed_letters: self.guessed_letters.clone(),
+            tries: self.tries,
+            attempts: self.attempts,
+        }
+    }
+}
+
+/// Runs the interactive REPL against `secret`, dispatching `guess`,
+/// `state`, `hint`, `add`, `save`, and `quit` commands until the game
+/// ends or the player quits.
+///
+/// # Arguments
+/// * `secret` - The word to be guessed this session
+/// * `dictionary` - Full word list; grows in place when `add` succeeds, and backs `hint`
+/// * `attempts` - Number of wrong guesses allowed before losing
+/// * `resumed` - A previously saved session to pick back up, if any
+/// * `dict_name` - Name of the dictionary currently selected, for persisting new words
+pub fn run(
+    secret: &str,
+    dictionary: &mut Vec<String>,
+    attempts: u32,
+    resumed: Option<GameState>,
+    dict_name: &str,
+) {
+    let mut session = match resumed {
+        Some(state) => Session {
+            secret: state.secret,
+            guessed_letters: state.guessed_letters,
+            tries: state.tries,
+            attempts: state.attempts,
+        },
+        None => Session {
+            secret: secret.to_string(),
+            guessed_letters: Vec::new(),
+            tries: 0,
+            attempts,
+        },
+    };
+
+    println!("Entering Snowman REPL. Commands: guess <letter>, state, hint, add <words...>, save, quit");
+    session.print_state();
+
+    loop {
+        let input: String = get_user_input("snowman> ");
+
+        match parse_command(&input) {
+            Command::Guess(letter) => {
+                match validate_guess(&letter, &session.guessed_letters) {
+                    Ok(_) => {
+                        session.guessed_letters.push(letter.clone());
+                        if !session.secret.contains(&letter) {
+                            session.tries += 1;
+                            draw_snowman(session.tries, session.attempts);
+                            println!("Wrong guess! {} tries left", session.attempts - session.tries);
+                        }
+                        session.print_state();
+                    }
+                    Err(e) => println!("{}", e),
+                }
+
+                if session.is_won() {
+                    println!("You won! The word was {}", session.secret);
+                    GameState::clear();
+                    break;
+                }
+                if session.is_lost() {
+                    println!("You lost. The word was {}", session.secret);
+                    GameState::clear();
+                    break;
+                }
+            }
+            Command::State => session.print_state(),
+            Command::Hint => {
+                let hint = solver::next_hint(
+                    &session.pattern(),
+                    &session.guessed_set(),
+                    &session.absent_set(),
+                    dictionary,
+                );
+                match hint {
+                    Some(letter) => println!("Hint: try '{}'", letter),
+                    None => println!("No hint available."),
+                }
+            }
+            Command::Add(words) => {
+                if words.is_empty() {
+                    println!("Usage: add <word> [words...]");
+                    continue;
+                }
+                for word in &words {
+                    match validate_new_word(word, dictionary) {
+                        Ok(_) => {
+                            dictionary::write_dictionary(dict_name, word);
+                            dictionary.push(word.clone());
+                            println!("Added {} to the dictionary!", word);
+                        }
+                        Err(e) => println!("{}", e),
+                    }
+                }
+            }
+            Command::Save => {
+                session.to_game_state().save();
+                println!("Game saved - resume later with --resume.");
+            }
+            Command::Quit => {
+                // Save on exit so an unfinished game can be resumed later
+                session.to_game_state().save();
+                println!("Goodbye!");
+                break;
+            }
+            Command::Unknown(line) => {
+                println!(
+                    "Unknown command '{}'. Try: guess <letter>, state, hint, add <words...>, save, quit",
+                    line
+                );
+            }
+        }
+    }
+}
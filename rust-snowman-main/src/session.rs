@@ -0,0 +1,161 @@
+/*
+ * Save/resume support: persists an in-progress game to a JSON session
+ * file so `--resume` can pick it back up later.
+ */
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+const SESSION_PATH: &str = "src/session.json";
+
+/// Everything needed to reconstruct an in-progress game.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GameState {
+    pub secret: String,
+    pub guessed_letters: Vec<String>,
+    pub tries: u32,
+    pub attempts: u32,
+}
+
+impl GameState {
+    /// Persists this game state, overwriting any previously saved session.
+    pub fn save(&self) {
+        self.save_to(SESSION_PATH);
+    }
+
+    /// Loads the saved session, if one exists and is still playable.
+    ///
+    /// Returns `None` without a message when no session file exists, and
+    /// falls back to `None` (printing a warning) for a corrupt or
+    /// partially-written session file, or one whose secret word is no
+    /// longer present in `dictionary` - in every case the caller should
+    /// just start a fresh game.
+    pub fn load(dictionary: &[String]) -> Option<Self> {
+        Self::load_from(SESSION_PATH, dictionary)
+    }
+
+    /// Removes the saved session, if any - called once a game is won or lost.
+    pub fn clear() {
+        let _ = fs::remove_file(SESSION_PATH);
+    }
+
+    /// Same as [`Self::save`], but against an arbitrary path - split out so tests
+    /// can exercise the save/load round trip without touching `SESSION_PATH`.
+    fn save_to(&self, path: &str) {
+        match serde_json::to_string_pretty(self) {
+            Ok(json) => {
+                if let Err(e) = fs::write(path, json) {
+                    eprintln!("Warning: couldn't save session: {}", e);
+                }
+            }
+            Err(e) => eprintln!("Warning: couldn't serialize session: {}", e),
+        }
+    }
+
+    /// Same as [`Self::load`], but against an arbitrary path - split out so tests
+    /// can exercise the fallback paths without touching `SESSION_PATH`.
+    fn load_from(path: &str, dictionary: &[String]) -> Option<Self> {
+        let path: &Path = Path::new(path);
+        if !path.exists() {
+            return None;
+        }
+
+        let contents: String = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) => {
+                eprintln!("Warning: couldn't read saved session ({}); starting a fresh game", e);
+                return None;
+            }
+        };
+
+        let state: GameState = match serde_json::from_str(&contents) {
+            Ok(state) => state,
+            Err(e) => {
+                eprintln!("Warning: saved session is corrupt ({}); starting a fresh game", e);
+                return None;
+            }
+        };
+
+        if !dictionary.contains(&state.secret) {
+            eprintln!(
+                "Warning: saved word '{}' is no longer in the dictionary; starting a fresh game",
+                state.secret
+            );
+            return None;
+        }
+
+        Some(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Gives each test its own session file under the OS temp dir, so
+    /// parallel test execution can't clobber a shared path.
+    fn temp_path(name: &str) -> String {
+        std::env::temp_dir()
+            .join(format!("snowman_session_test_{}.json", name))
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    #[test]
+    fn save_then_load_round_trips() {
+        let path = temp_path("round_trip");
+        let dictionary = vec!["igloo".to_string()];
+        let state = GameState {
+            secret: "igloo".to_string(),
+            guessed_letters: vec!["i".to_string(), "g".to_string()],
+            tries: 1,
+            attempts: 6,
+        };
+
+        state.save_to(&path);
+        let loaded = GameState::load_from(&path, &dictionary).expect("session should load");
+
+        assert_eq!(loaded.secret, state.secret);
+        assert_eq!(loaded.guessed_letters, state.guessed_letters);
+        assert_eq!(loaded.tries, state.tries);
+        assert_eq!(loaded.attempts, state.attempts);
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn load_returns_none_for_missing_file() {
+        let path = temp_path("missing");
+        let _ = fs::remove_file(&path);
+
+        assert!(GameState::load_from(&path, &[]).is_none());
+    }
+
+    #[test]
+    fn load_returns_none_for_corrupt_file() {
+        let path = temp_path("corrupt");
+        fs::write(&path, "not valid json").unwrap();
+
+        assert!(GameState::load_from(&path, &[]).is_none());
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn load_returns_none_for_stale_word() {
+        let path = temp_path("stale_word");
+        let dictionary = vec!["penguin".to_string()];
+        let state = GameState {
+            secret: "igloo".to_string(),
+            guessed_letters: Vec::new(),
+            tries: 0,
+            attempts: 6,
+        };
+
+        state.save_to(&path);
+        assert!(GameState::load_from(&path, &dictionary).is_none());
+
+        let _ = fs::remove_file(&path);
+    }
+}
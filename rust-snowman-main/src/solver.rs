@@ -0,0 +1,217 @@
+/*
+ * Automatic solver: plays Snowman against a known secret word by always
+ * guessing the letter that appears in the most surviving candidate
+ * words, then pruning the candidate set by the result.
+ */
+
+use std::collections::{HashMap, HashSet};
+
+/// Outcome of one solver run against a single secret word.
+#[derive(Debug, Clone, Copy)]
+pub struct SolveResult {
+    /// Whether the solver uncovered the whole word within the attempt budget.
+    pub solved: bool,
+    /// Number of wrong guesses the solver made before stopping.
+    pub wrong_guesses: u32,
+}
+
+/// Plays Snowman automatically against `secret`.
+///
+/// Starts with every `dictionary` word of the same length as `secret` as
+/// the live candidate set, then on each turn guesses whichever
+/// not-yet-guessed letter appears in the most candidates. Candidates are
+/// pruned after every guess: kept only if their revealed letters match
+/// the exposed pattern (when the guess hits), or dropped entirely if
+/// they contain the guessed letter (when it misses).
+///
+/// # Arguments
+/// * `secret` - The word being solved for
+/// * `dictionary` - Word list to draw same-length candidates from
+/// * `attempts` - Number of wrong guesses allowed before losing
+/// * `verbose` - Whether to print each guess and the resulting pattern
+pub fn solve(secret: &str, dictionary: &[String], attempts: u32, verbose: bool) -> SolveResult {
+    let secret_len: usize = secret.chars().count();
+    let mut candidates: Vec<&String> = dictionary
+        .iter()
+        .filter(|word| word.chars().count() == secret_len)
+        .collect();
+
+    let mut guessed: HashSet<char> = HashSet::new();
+    let mut pattern: Vec<Option<char>> = vec![None; secret_len];
+    let mut wrong_guesses: u32 = 0;
+
+    while pattern.iter().any(|slot| slot.is_none()) {
+        if wrong_guesses >= attempts {
+            if verbose {
+                println!("Failed to solve '{}' ({} wrong guesses)", secret, wrong_guesses);
+            }
+            return SolveResult {
+                solved: false,
+                wrong_guesses,
+            };
+        }
+
+        let letter = match best_guess(&candidates, &guessed) {
+            Some(letter) => letter,
+            None => {
+                if verbose {
+                    println!("Solver ran out of candidates for '{}'", secret);
+                }
+                return SolveResult {
+                    solved: false,
+                    wrong_guesses,
+                };
+            }
+        };
+        guessed.insert(letter);
+
+        if secret.contains(letter) {
+            for (slot, c) in pattern.iter_mut().zip(secret.chars()) {
+                if c == letter {
+                    *slot = Some(letter);
+                }
+            }
+            candidates.retain(|word| matches_pattern(word, &pattern));
+        } else {
+            wrong_guesses += 1;
+            candidates.retain(|word| !word.contains(letter));
+        }
+
+        if verbose {
+            println!("Guess '{}' -> {}", letter, render_pattern(&pattern));
+        }
+    }
+
+    if verbose {
+        println!("Solved '{}' in {} wrong guesses", secret, wrong_guesses);
+    }
+    SolveResult {
+        solved: true,
+        wrong_guesses,
+    }
+}
+
+/// Suggests the next letter to guess for a game already in progress,
+/// given what's been revealed so far. Used by the REPL's `hint` command.
+///
+/// # Arguments
+/// * `pattern` - Revealed slots of the secret word so far (`None` = still hidden)
+/// * `guessed` - Every letter guessed so far, right or wrong
+/// * `absent` - Guessed letters confirmed not to be in the secret word
+/// * `dictionary` - Word list to draw same-length candidates from
+pub fn next_hint(
+    pattern: &[Option<char>],
+    guessed: &HashSet<char>,
+    absent: &HashSet<char>,
+    dictionary: &[String],
+) -> Option<char> {
+    let candidates: Vec<&String> = dictionary
+        .iter()
+        .filter(|word| word.chars().count() == pattern.len())
+        .filter(|word| matches_pattern(word, pattern))
+        .filter(|word| !absent.iter().any(|&letter| word.contains(letter)))
+        .collect();
+
+    best_guess(&candidates, guessed)
+}
+
+/// Picks the not-yet-guessed letter that appears in the most `candidates`.
+fn best_guess(candidates: &[&String], guessed: &HashSet<char>) -> Option<char> {
+    let mut candidate_counts: HashMap<char, usize> = HashMap::new();
+
+    for word in candidates {
+        let letters_in_word: HashSet<char> = word.chars().collect();
+        for letter in letters_in_word {
+            if !guessed.contains(&letter) {
+                *candidate_counts.entry(letter).or_insert(0) += 1;
+            }
+        }
+    }
+
+    candidate_counts
+        .into_iter()
+        .max_by_key(|&(_, count)| count)
+        .map(|(letter, _)| letter)
+}
+
+/// Whether `word`'s letters agree with every revealed slot in `pattern`.
+fn matches_pattern(word: &str, pattern: &[Option<char>]) -> bool {
+    word.chars().count() == pattern.len()
+        && word.chars().zip(pattern.iter()).all(|(c, slot)| match slot {
+            Some(revealed) => c == *revealed,
+            None => true,
+        })
+}
+
+/// Renders a pattern as the masked word, e.g. `sn_wma_`.
+fn render_pattern(pattern: &[Option<char>]) -> String {
+    pattern.iter().map(|slot| slot.unwrap_or('_')).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(list: &[&str]) -> Vec<String> {
+        list.iter().map(|w| w.to_string()).collect()
+    }
+
+    #[test]
+    fn best_guess_picks_most_common_unguessed_letter() {
+        let list = words(&["cat", "car", "can"]);
+        let candidates: Vec<&String> = list.iter().collect();
+        let guessed: HashSet<char> = HashSet::new();
+
+        // 'c' and 'a' both appear in all three candidates; 'c' wins ties by
+        // being the first encountered in HashMap iteration isn't guaranteed,
+        // so just assert the winner is one of the maximally-frequent letters.
+        let guess = best_guess(&candidates, &guessed).unwrap();
+        assert!(['c', 'a'].contains(&guess));
+    }
+
+    #[test]
+    fn best_guess_ignores_already_guessed_letters() {
+        let list = words(&["cat", "car", "can"]);
+        let candidates: Vec<&String> = list.iter().collect();
+        let guessed: HashSet<char> = ['c', 'a'].into_iter().collect();
+
+        let guess = best_guess(&candidates, &guessed).unwrap();
+        assert!(['t', 'r', 'n'].contains(&guess));
+    }
+
+    #[test]
+    fn best_guess_returns_none_for_no_candidates() {
+        let candidates: Vec<&String> = Vec::new();
+        let guessed: HashSet<char> = HashSet::new();
+        assert_eq!(best_guess(&candidates, &guessed), None);
+    }
+
+    #[test]
+    fn matches_pattern_checks_revealed_slots_only() {
+        let pattern = vec![Some('c'), None, Some('t')];
+        assert!(matches_pattern("cat", &pattern));
+        assert!(matches_pattern("cot", &pattern));
+        assert!(!matches_pattern("cab", &pattern));
+    }
+
+    #[test]
+    fn matches_pattern_rejects_wrong_length() {
+        let pattern = vec![Some('c'), None, Some('t')];
+        assert!(!matches_pattern("ct", &pattern));
+    }
+
+    #[test]
+    fn solve_finds_the_secret_within_attempts() {
+        let dictionary = words(&["cat", "car", "can", "cap", "bat", "bag"]);
+        let result = solve("cat", &dictionary, 6, false);
+        assert!(result.solved);
+    }
+
+    #[test]
+    fn solve_reports_failure_when_candidates_run_out() {
+        // No dictionary words at all, so the solver has no candidate to draw a guess from.
+        let dictionary: Vec<String> = Vec::new();
+        let result = solve("cat", &dictionary, 6, false);
+        assert!(!result.solved);
+    }
+}